@@ -2,7 +2,7 @@ use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::B256;
 use reth_fs_util::FsPathError;
 use std::{
-    net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6, ToSocketAddrs},
     path::Path,
     str::FromStr,
     time::Duration,
@@ -51,22 +51,467 @@ pub enum SocketAddressParsingError {
     /// Failed to parse the address
     #[error("could not parse socket address from {0}")]
     Parse(String),
+    /// Failed to parse the literal at a specific byte offset
+    #[error("could not parse socket address from {input:?}: unexpected input at byte {position}")]
+    ParseAt {
+        /// The original input that failed to parse.
+        input: String,
+        /// The byte offset of the first character that could not be consumed.
+        position: usize,
+    },
     /// Failed to parse port
     #[error("could not parse port: {0}")]
     Port(#[from] std::num::ParseIntError),
+    /// Failed to normalize an internationalized hostname to ASCII.
+    #[error("could not normalize hostname {0:?} to ASCII")]
+    Idna(String),
 }
 
-/// Parse a [`SocketAddr`] from a `str`.
+/// A parsed host portion of a socket address.
+///
+/// Hostnames are normalized to ASCII (Punycode) via IDNA so that internationalized domain names are
+/// handled consistently across platforms instead of being passed through to libc verbatim. CLI
+/// parsers that accept a host (trusted-peer and bootnode lists, for example) can reuse
+/// [`parse_host`] to get the same normalization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Host {
+    /// An IPv4 literal.
+    Ipv4(Ipv4Addr),
+    /// An IPv6 literal.
+    Ipv6(Ipv6Addr),
+    /// A domain name, already normalized to ASCII.
+    Domain(String),
+}
+
+impl Host {
+    /// Returns the host as a `String` suitable for handing to a resolver: the ASCII domain for
+    /// [`Host::Domain`], or the literal representation for the IP variants.
+    pub fn to_host_string(&self) -> String {
+        match self {
+            Self::Ipv4(ip) => ip.to_string(),
+            Self::Ipv6(ip) => ip.to_string(),
+            Self::Domain(domain) => domain.clone(),
+        }
+    }
+}
+
+/// Parse the host portion of an address into a [`Host`].
+///
+/// IP literals are recognised with the same network-free parser used by [`parse_socket_address`].
+/// Anything else is treated as a domain and run through IDNA [`domain_to_ascii`](idna::domain_to_ascii),
+/// which applies Punycode encoding and rejects empty or otherwise invalid labels.
+pub fn parse_host(value: &str) -> eyre::Result<Host, SocketAddressParsingError> {
+    if value.is_empty() {
+        return Err(SocketAddressParsingError::Empty)
+    }
+
+    let mut parser = Parser::new(value.as_bytes());
+    if let Some(ip) = parser.read_till_eof(Parser::read_ip_addr) {
+        return Ok(match ip {
+            IpAddr::V4(ip) => Host::Ipv4(ip),
+            IpAddr::V6(ip) => Host::Ipv6(ip),
+        })
+    }
+
+    let ascii =
+        idna::domain_to_ascii(value).map_err(|_| SocketAddressParsingError::Idna(value.to_string()))?;
+    if ascii.is_empty() {
+        return Err(SocketAddressParsingError::Idna(value.to_string()))
+    }
+    Ok(Host::Domain(ascii))
+}
+
+/// A minimal recursive-descent parser over a byte slice.
+///
+/// This mirrors the approach taken by the standard library's `core::net::parser`: the parser holds
+/// the unconsumed tail of the input in [`Parser::state`] and advances it as tokens are read.
+/// [`Parser::read_atomically`] snapshots the state before running an inner closure and restores it
+/// when the closure returns `None`, so a partially matched alternative leaves no trace.
+///
+/// The parser never touches the network; it only recognises IP literals, ports and zone
+/// identifiers.
+struct Parser<'a> {
+    /// The unconsumed portion of the input.
+    state: &'a [u8],
+    /// The full length of the original input, used to compute absolute offsets.
+    input_len: usize,
+    /// The furthest byte offset any (possibly-abandoned) alternative managed to consume up to. This
+    /// survives the state restore performed by [`Parser::read_atomically`] so a failed parse can
+    /// still point at the offending position.
+    high_water: usize,
+}
+
+impl<'a> Parser<'a> {
+    const fn new(input: &'a [u8]) -> Self {
+        Self { state: input, input_len: input.len(), high_water: 0 }
+    }
+
+    /// Run `inner` over the remaining input, restoring the state on failure so the next alternative
+    /// sees the original input.
+    ///
+    /// Before restoring, the furthest offset reached by `inner` is folded into [`Self::high_water`]
+    /// so the information is not lost when the state snapshot is rolled back.
+    fn read_atomically<T, F>(&mut self, inner: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self) -> Option<T>,
+    {
+        let state = self.state;
+        let result = inner(self);
+        let reached = self.input_len - self.state.len();
+        if reached > self.high_water {
+            self.high_water = reached;
+        }
+        if result.is_none() {
+            self.state = state;
+        }
+        result
+    }
+
+    /// Run `inner` and only succeed if it consumed the entire input.
+    fn read_till_eof<T, F>(&mut self, inner: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self) -> Option<T>,
+    {
+        self.read_atomically(move |p| inner(p).filter(|_| p.state.is_empty()))
+    }
+
+    /// Peek at the next byte without consuming it.
+    fn peek_char(&self) -> Option<u8> {
+        self.state.first().copied()
+    }
+
+    /// Consume the next byte if it satisfies `f`.
+    fn read_char<F: FnOnce(u8) -> bool>(&mut self, f: F) -> Option<u8> {
+        self.read_atomically(|p| {
+            let (&b, tail) = p.state.split_first()?;
+            if f(b) {
+                p.state = tail;
+                Some(b)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Consume the given literal byte.
+    fn read_given_char(&mut self, c: u8) -> Option<()> {
+        self.read_char(|b| b == c).map(drop)
+    }
+
+    /// Read an unsigned integer in the given `radix`, accumulating with checked arithmetic so that
+    /// out-of-range values (e.g. a `256` octet) are rejected rather than wrapping.
+    ///
+    /// At most `max_digits` digits are consumed. When `allow_leading_zero` is `false` a multi-digit
+    /// run beginning with `0` is rejected, matching IP-literal grammar.
+    fn read_number<T: CheckedNum>(
+        &mut self,
+        radix: u32,
+        max_digits: Option<usize>,
+        allow_leading_zero: bool,
+    ) -> Option<T> {
+        self.read_atomically(|p| {
+            let mut acc = T::ZERO;
+            let mut digits = 0usize;
+            let mut leading_zero = false;
+
+            while let Some(digit) = p.peek_char().and_then(|b| (b as char).to_digit(radix)) {
+                if digits == 0 && digit == 0 {
+                    leading_zero = true;
+                } else if leading_zero && !allow_leading_zero {
+                    return None;
+                }
+
+                acc = acc.checked_mul(radix)?.checked_add(digit)?;
+                digits += 1;
+                p.state = &p.state[1..];
+
+                if let Some(max) = max_digits {
+                    if digits == max {
+                        break;
+                    }
+                }
+            }
+
+            (digits > 0).then_some(acc)
+        })
+    }
+
+    /// Read an IPv4 address in dotted-decimal notation.
+    fn read_ipv4_addr(&mut self) -> Option<Ipv4Addr> {
+        self.read_atomically(|p| {
+            let mut octets = [0u8; 4];
+            for (i, slot) in octets.iter_mut().enumerate() {
+                if i > 0 {
+                    p.read_given_char(b'.')?;
+                }
+                *slot = p.read_number(10, Some(3), false)?;
+            }
+            Some(Ipv4Addr::from(octets))
+        })
+    }
+
+    /// Read an IPv6 address, handling `::` compression and an embedded IPv4 tail.
+    fn read_ipv6_addr(&mut self) -> Option<Ipv6Addr> {
+        /// Fold the head and tail groups around a `::` gap into the final 16 bytes.
+        fn assemble(head: &[u16], tail: &[u16]) -> Ipv6Addr {
+            let mut groups = [0u16; 8];
+            groups[..head.len()].copy_from_slice(head);
+            let tail_start = 8 - tail.len();
+            groups[tail_start..].copy_from_slice(tail);
+            Ipv6Addr::from(groups)
+        }
+
+        self.read_atomically(|p| {
+            // Read up to `limit` groups into `groups`. An embedded IPv4 tail is only accepted when
+            // it occupies the final two group slots and terminates the address, so a stray `:`
+            // cannot follow it. Returns the number of groups written and whether the run ended with
+            // an IPv4 tail (which forbids any further groups or `::` compression).
+            fn read_groups(p: &mut Parser<'_>, groups: &mut [u16; 8], limit: usize) -> (usize, bool) {
+                let mut i = 0;
+                while i < limit {
+                    // Try an embedded IPv4 tail first (only valid with room for two groups). It must
+                    // end the address: if it is immediately followed by another group separator it
+                    // is not a tail, so back it out and parse the leading number as a hex group.
+                    if i + 2 <= limit {
+                        if let Some(v4) =
+                            p.read_atomically(|p| p.read_ipv4_addr().filter(|_| p.peek_char() != Some(b':')))
+                        {
+                            let bits = u32::from(v4);
+                            groups[i] = (bits >> 16) as u16;
+                            groups[i + 1] = bits as u16;
+                            i += 2;
+                            return (i, true);
+                        }
+                    }
+
+                    let group = p.read_number::<u32>(16, Some(4), true);
+                    match group {
+                        Some(g) => {
+                            groups[i] = g as u16;
+                            i += 1;
+                        }
+                        None => break,
+                    }
+
+                    // A `:` is only consumed when a further group follows it. A trailing `:` or the
+                    // first colon of `::` is left in place: the caller reads `::` itself, and
+                    // `read_till_eof` rejects any other leftover.
+                    if p.read_atomically(|p| {
+                        p.read_given_char(b':')
+                            .filter(|_| p.peek_char().is_some_and(|b| b.is_ascii_hexdigit()))
+                    })
+                    .is_none()
+                    {
+                        break;
+                    }
+                }
+                (i, false)
+            }
+
+            let mut head = [0u16; 8];
+
+            // Leading `::`.
+            if p.read_given_char(b':').is_some() {
+                p.read_given_char(b':')?;
+                if p.peek_char().is_none() {
+                    return Some(Ipv6Addr::UNSPECIFIED);
+                }
+                let mut tail = [0u16; 8];
+                let (n, _) = read_groups(p, &mut tail, 8);
+                // A `::` must compress at least one zero group; a full set of tail groups is not a
+                // valid compression.
+                if n == 8 {
+                    return None;
+                }
+                return Some(assemble(&[], &tail[..n]));
+            }
+
+            let (head_len, head_v4) = read_groups(p, &mut head, 8);
+
+            // A `::` follows the head groups as two consecutive colons. An IPv4 tail always ends the
+            // address, so compression cannot follow it.
+            if !head_v4 &&
+                p.read_atomically(|p| p.read_given_char(b':').and_then(|()| p.read_given_char(b':')))
+                    .is_some()
+            {
+                if p.peek_char().is_none() {
+                    return (head_len < 8).then(|| assemble(&head[..head_len], &[]));
+                }
+                let mut tail = [0u16; 8];
+                let tail_limit = 8 - head_len;
+                let (n, _) = read_groups(p, &mut tail, tail_limit);
+                // A `::` must compress at least one zero group, so the head and tail together may
+                // not already account for all 8 groups.
+                if head_len + n == 8 {
+                    return None;
+                }
+                return Some(assemble(&head[..head_len], &tail[..n]));
+            }
+
+            (head_len == 8).then(|| Ipv6Addr::from(head))
+        })
+    }
+
+    /// Read either an IPv4 or an IPv6 address.
+    fn read_ip_addr(&mut self) -> Option<IpAddr> {
+        self.read_ipv4_addr()
+            .map(IpAddr::V4)
+            .or_else(|| self.read_ipv6_addr().map(IpAddr::V6))
+    }
+
+    /// Read a `%zone` scope identifier and return its bytes.
+    fn read_scope_id(&mut self) -> Option<&'a [u8]> {
+        self.read_atomically(|p| {
+            p.read_given_char(b'%')?;
+            let start = p.state;
+            let mut len = 0;
+            while let Some(b) = p.peek_char() {
+                if b == b':' || b == b']' {
+                    break;
+                }
+                p.state = &p.state[1..];
+                len += 1;
+            }
+            (len > 0).then(|| &start[..len])
+        })
+    }
+
+    /// Read a `u16` port.
+    fn read_port(&mut self) -> Option<u16> {
+        self.read_number(10, Some(5), true)
+    }
+}
+
+/// Trait over the integer types the number reader accumulates into, providing checked arithmetic.
+trait CheckedNum: Copy {
+    const ZERO: Self;
+    fn checked_mul(self, rhs: u32) -> Option<Self>;
+    fn checked_add(self, rhs: u32) -> Option<Self>;
+}
+
+macro_rules! impl_checked_num {
+    ($($t:ty),*) => {$(
+        impl CheckedNum for $t {
+            const ZERO: Self = 0;
+            fn checked_mul(self, rhs: u32) -> Option<Self> {
+                <$t>::checked_mul(self, rhs as $t)
+            }
+            fn checked_add(self, rhs: u32) -> Option<Self> {
+                <$t>::checked_add(self, rhs as $t)
+            }
+        }
+    )*};
+}
+
+impl_checked_num!(u8, u16, u32);
+
+/// Parse a [`SocketAddr`] from a `str` using a hand-written, network-free literal parser.
+///
+/// The following formats are accepted:
+///
+/// - A bare `u16` or a value starting with a single `:` (not `::`) is treated as a port, with the
+///   host set to `localhost` (`127.0.0.1`).
+/// - `localhost:<port>` is treated as the loopback address on `<port>`.
+/// - An IPv4 or IPv6 literal optionally followed by `:<port>`, including bracketed `[v6]:port`,
+///   `::` compression, embedded IPv4 tails (`2001:db8::192.0.2.33`) and `%zone` scope suffixes. A
+///   numeric zone becomes the resulting [`SocketAddrV6`] scope id; a named zone (`%eth0`) is
+///   accepted but mapped to scope id `0`, since resolving an interface index requires the system.
+///
+/// Unlike [`parse_socket_address_allow_dns`] this never performs a DNS lookup; hostnames are
+/// rejected with a [`SocketAddressParsingError::ParseAt`] pointing at the offending position.
+///
+/// An error is returned if the value is empty.
+pub fn parse_socket_address(value: &str) -> eyre::Result<SocketAddr, SocketAddressParsingError> {
+    if value.is_empty() {
+        return Err(SocketAddressParsingError::Empty)
+    }
+
+    // Port-only shortcuts default to loopback. A leading `::` is IPv6 compression, not a port.
+    if let Some(port) = value
+        .strip_prefix(':')
+        .filter(|rest| !rest.starts_with(':'))
+        .or_else(|| value.strip_prefix("localhost:"))
+    {
+        let port: u16 = port.parse()?;
+        return Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+    }
+    if let Ok(port) = value.parse::<u16>() {
+        return Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+    }
+
+    parse_socket_addr_literal(value).map_err(|position| SocketAddressParsingError::ParseAt {
+        input: value.to_string(),
+        position,
+    })
+}
+
+/// Parse a `host:port` literal, where `host` is an IP literal. A bare IPv4 or IPv6 literal without
+/// a port is accepted with port `0`.
+///
+/// Returns `Err` with the byte offset of the first unconsumed character when the input is not such
+/// a literal.
+fn parse_socket_addr_literal(value: &str) -> Result<SocketAddr, usize> {
+    let bytes = value.as_bytes();
+    let mut parser = Parser::new(bytes);
+
+    let result = parser.read_till_eof(|p| {
+        // Bracketed IPv6: `[addr%zone]:port`.
+        if p.read_given_char(b'[').is_some() {
+            let addr = p.read_ipv6_addr()?;
+            let zone = p.read_scope_id();
+            p.read_given_char(b']')?;
+            p.read_given_char(b':')?;
+            let port = p.read_port()?;
+            return Some(v6_socket_addr(addr, zone, port));
+        }
+
+        // Unbracketed IPv4 (with optional `:port`) or bare IPv6 (no port).
+        if let Some(v4) = p.read_ipv4_addr() {
+            let port = if p.read_given_char(b':').is_some() { p.read_port()? } else { 0 };
+            return Some(SocketAddr::new(IpAddr::V4(v4), port));
+        }
+
+        match p.read_ip_addr()? {
+            IpAddr::V4(v4) => Some(SocketAddr::new(IpAddr::V4(v4), 0)),
+            IpAddr::V6(v6) => {
+                let zone = p.read_scope_id();
+                Some(v6_socket_addr(v6, zone, 0))
+            }
+        }
+    });
+
+    result.ok_or(parser.high_water)
+}
+
+/// Build an IPv6 [`SocketAddr`] carrying a `%zone` scope id.
+///
+/// A numeric zone is used directly as the scope id. A named zone (e.g. `eth0`) cannot be resolved
+/// to an interface index without touching the system, so it is accepted but mapped to scope id `0`.
+fn v6_socket_addr(addr: Ipv6Addr, zone: Option<&[u8]>, port: u16) -> SocketAddr {
+    let scope_id = zone
+        .and_then(|z| std::str::from_utf8(z).ok())
+        .and_then(|z| z.parse::<u32>().ok())
+        .unwrap_or(0);
+    SocketAddr::V6(SocketAddrV6::new(addr, port, 0, scope_id))
+}
+
+/// Parse a [`SocketAddr`] from a `str`, resolving hostnames via the system resolver.
+///
+/// This retains the original, DNS-resolving behavior of [`parse_socket_address`] for callers that
+/// genuinely accept hostnames. Prefer [`parse_socket_address`] when only IP literals are expected,
+/// since it performs no blocking lookups.
 ///
 /// The following formats are checked:
 ///
 /// - If the value can be parsed as a `u16` or starts with `:` it is considered a port, and the
 ///   hostname is set to `localhost`.
-/// - If the value contains `:` it is assumed to be the format `<host>:<port>`
-/// - Otherwise it is assumed to be a hostname
+/// - If the value contains `:` it is assumed to be the format `<host>:<port>`.
+/// - Otherwise it is assumed to be a hostname.
 ///
 /// An error is returned if the value is empty.
-pub fn parse_socket_address(value: &str) -> eyre::Result<SocketAddr, SocketAddressParsingError> {
+pub fn parse_socket_address_allow_dns(
+    value: &str,
+) -> eyre::Result<SocketAddr, SocketAddressParsingError> {
     if value.is_empty() {
         return Err(SocketAddressParsingError::Empty)
     }
@@ -78,6 +523,23 @@ pub fn parse_socket_address(value: &str) -> eyre::Result<SocketAddr, SocketAddre
     if let Ok(port) = value.parse() {
         return Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
     }
+
+    // IP literals - including bare/bracketed IPv6 and `[v6]:port` - need no IDNA normalization and
+    // are handed straight to the resolver, preserving the baseline behavior.
+    if parse_socket_addr_literal(value).is_err() && parse_ip_literal(value).is_err() {
+        // A genuine hostname: normalize it to ASCII before resolution, splitting off a trailing
+        // port (IPv4-style or none; bracketed IPv6 was handled above) so only the domain is run
+        // through IDNA.
+        let resolve_target = match value.rsplit_once(':') {
+            Some((host, port)) => format!("{}:{}", parse_host(host)?.to_host_string(), port),
+            None => parse_host(value)?.to_host_string(),
+        };
+        return resolve_target
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| SocketAddressParsingError::Parse(value.to_string()))
+    }
+
     value
         .to_socket_addrs()?
         .next()
@@ -89,21 +551,268 @@ pub fn read_json_from_file<T: serde::de::DeserializeOwned>(path: &str) -> Result
     reth_fs_util::read_json_file(Path::new(path))
 }
 
-/// Parses an ether value from a string.
+/// Error thrown while parsing an IP filter (CIDR block, range or single address).
+#[derive(thiserror::Error, Debug)]
+pub enum IpFilterParsingError {
+    /// Input must not be empty
+    #[error("cannot parse IP filter from empty string")]
+    Empty,
+    /// Failed to parse the address literal
+    #[error("could not parse IP address from {0:?}")]
+    Address(String),
+    /// Prefix length exceeds the address family's width
+    #[error("prefix length /{prefix} is out of range for an IPv{version} address")]
+    PrefixLength {
+        /// The offending prefix length.
+        prefix: u8,
+        /// The IP version the prefix was applied to (`4` or `6`).
+        version: u8,
+    },
+    /// A range's endpoints belong to different address families
+    #[error("range endpoints {start:?} and {end:?} are not the same address family")]
+    MixedRange {
+        /// The start endpoint.
+        start: IpAddr,
+        /// The end endpoint.
+        end: IpAddr,
+    },
+    /// A range's start is greater than its end
+    #[error("range start {start:?} is greater than end {end:?}")]
+    InvertedRange {
+        /// The start endpoint.
+        start: IpAddr,
+        /// The end endpoint.
+        end: IpAddr,
+    },
+}
+
+/// A CIDR block: a base address together with a network prefix length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IpCidr {
+    /// The base address of the block.
+    pub addr: IpAddr,
+    /// The network prefix length in bits (bounded by 32 for IPv4, 128 for IPv6).
+    pub prefix_len: u8,
+}
+
+impl IpCidr {
+    /// Returns `true` if `ip` falls within this block.
+    ///
+    /// Addresses of a different family are never contained.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(base), IpAddr::V4(ip)) => {
+                masked(u32::from(base).into(), self.prefix_len, 32) ==
+                    masked(u32::from(ip).into(), self.prefix_len, 32)
+            }
+            (IpAddr::V6(base), IpAddr::V6(ip)) => {
+                masked(u128::from(base), self.prefix_len, 128) ==
+                    masked(u128::from(ip), self.prefix_len, 128)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Keep only the top `prefix_len` bits of a `width`-bit address.
+fn masked(bits: u128, prefix_len: u8, width: u8) -> u128 {
+    if prefix_len == 0 {
+        return 0;
+    }
+    let shift = width - prefix_len;
+    (bits >> shift) << shift
+}
+
+/// A matcher over IP addresses, built from a CIDR block, an inclusive range or a single address.
 ///
-/// The amount in eth like "1.05" will be interpreted in wei (1.05 * 1e18).
-/// Supports both decimal and integer inputs.
+/// This is the value type behind network allow/deny CLI flags such as `--net.allow 10.0.0.0/8`; the
+/// networking layer consults [`IpFilter::contains`] to decide whether a peer address matches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpFilter {
+    /// Matches every address within a CIDR block.
+    Cidr(IpCidr),
+    /// Matches every address in the inclusive range `start..=end`.
+    Range {
+        /// The first address in the range.
+        start: IpAddr,
+        /// The last address in the range.
+        end: IpAddr,
+    },
+    /// Matches a single address.
+    Single(IpAddr),
+}
+
+impl IpFilter {
+    /// Returns `true` if `ip` matches this filter.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match self {
+            Self::Cidr(cidr) => cidr.contains(ip),
+            Self::Single(addr) => *addr == ip,
+            Self::Range { start, end } => match (start, end, ip) {
+                (IpAddr::V4(s), IpAddr::V4(e), IpAddr::V4(ip)) => {
+                    (u32::from(*s)..=u32::from(*e)).contains(&u32::from(ip))
+                }
+                (IpAddr::V6(s), IpAddr::V6(e), IpAddr::V6(ip)) => {
+                    (u128::from(*s)..=u128::from(*e)).contains(&u128::from(ip))
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Parse a CIDR block such as `192.168.0.0/16` or `fe80::/10`.
+///
+/// The address is recognised with the same network-free literal parser used by
+/// [`parse_socket_address`], and the prefix length is validated against the address family's width
+/// (32 for IPv4, 128 for IPv6) using checked arithmetic so an over-long prefix is rejected rather
+/// than wrapping.
+pub fn parse_ip_cidr(value: &str) -> eyre::Result<IpCidr, IpFilterParsingError> {
+    if value.is_empty() {
+        return Err(IpFilterParsingError::Empty)
+    }
+
+    let (addr_part, prefix_part) =
+        value.split_once('/').ok_or_else(|| IpFilterParsingError::Address(value.to_string()))?;
+
+    let addr = parse_ip_literal(addr_part)?;
+    let max = if addr.is_ipv4() { 32u8 } else { 128u8 };
+
+    let prefix_len: u8 =
+        prefix_part.parse().map_err(|_| IpFilterParsingError::Address(value.to_string()))?;
+    if prefix_len > max {
+        return Err(IpFilterParsingError::PrefixLength {
+            prefix: prefix_len,
+            version: if addr.is_ipv4() { 4 } else { 6 },
+        })
+    }
+
+    Ok(IpCidr { addr, prefix_len })
+}
+
+/// Parse a network allow/deny entry into an [`IpFilter`].
+///
+/// Accepts a CIDR block (`10.0.0.0/8`), an inclusive range (`10.0.0.1-10.0.0.50`) or a single
+/// address (`192.168.1.1`).
+pub fn parse_socket_filter(value: &str) -> eyre::Result<IpFilter, IpFilterParsingError> {
+    if value.is_empty() {
+        return Err(IpFilterParsingError::Empty)
+    }
+
+    if value.contains('/') {
+        return Ok(IpFilter::Cidr(parse_ip_cidr(value)?))
+    }
+
+    if let Some((start, end)) = value.split_once('-') {
+        let start = parse_ip_literal(start)?;
+        let end = parse_ip_literal(end)?;
+        match (start, end) {
+            (IpAddr::V4(s), IpAddr::V4(e)) if u32::from(s) > u32::from(e) => {
+                return Err(IpFilterParsingError::InvertedRange { start, end })
+            }
+            (IpAddr::V6(s), IpAddr::V6(e)) if u128::from(s) > u128::from(e) => {
+                return Err(IpFilterParsingError::InvertedRange { start, end })
+            }
+            (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_)) => {}
+            _ => return Err(IpFilterParsingError::MixedRange { start, end }),
+        }
+        return Ok(IpFilter::Range { start, end })
+    }
+
+    Ok(IpFilter::Single(parse_ip_literal(value)?))
+}
+
+/// Parse a bare IP literal (no port) using the atomic literal parser.
+fn parse_ip_literal(value: &str) -> Result<IpAddr, IpFilterParsingError> {
+    let mut parser = Parser::new(value.as_bytes());
+    parser
+        .read_till_eof(Parser::read_ip_addr)
+        .ok_or_else(|| IpFilterParsingError::Address(value.to_string()))
+}
+
+/// Parses an ether value from a string into an exact amount of wei.
+///
+/// The amount is interpreted with integer/fixed-point arithmetic, so values like `"0.1"` or a
+/// single wei are represented exactly and overflow is reported instead of silently wrapping (as the
+/// previous `f64` implementation did).
+///
+/// A trailing unit suffix selects the scale; with no suffix the value is in ether. Supported units
+/// are `wei` (0 decimals), `gwei` (9 decimals) and `ether` (18 decimals). The fractional part may
+/// not have more digits than the unit's precision.
 ///
 /// # Examples
-/// - "1.05" -> 1.05 ETH = 1.05 * 10^18 wei
-/// - "2" -> 2 ETH = 2 * 10^18 wei
+/// - "1.05" -> 1.05 ether = 1_050_000_000_000_000_000 wei
+/// - "2ether" -> 2 ether = 2_000_000_000_000_000_000 wei
+/// - "1 gwei" -> 1_000_000_000 wei
+/// - "500000 wei" -> 500_000 wei
 pub fn parse_ether_value(value: &str) -> eyre::Result<u128> {
-    let eth = value.parse::<f64>()?;
-    if eth.is_sign_negative() {
+    let value = value.trim();
+    if value.starts_with('-') {
         return Err(eyre::eyre!("Ether value cannot be negative"))
     }
-    let wei = eth * 1e18;
-    Ok(wei as u128)
+
+    // Split off an optional unit suffix (with or without a separating space).
+    let (amount, decimals) = if let Some(amount) = strip_unit(value, "ether") {
+        (amount, 18u32)
+    } else if let Some(amount) = strip_unit(value, "gwei") {
+        (amount, 9)
+    } else if let Some(amount) = strip_unit(value, "wei") {
+        (amount, 0)
+    } else {
+        (value, 18)
+    };
+
+    let (int_part, frac_part) = match amount.split_once('.') {
+        Some((int, frac)) => (int, frac),
+        None => (amount, ""),
+    };
+
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(eyre::eyre!("invalid ether value: {value:?}"))
+    }
+    if frac_part.len() as u32 > decimals {
+        return Err(eyre::eyre!(
+            "too many fractional digits for the given unit: {value:?} (max {decimals})"
+        ))
+    }
+
+    let scale = 10u128.checked_pow(decimals).expect("unit precision fits in u128");
+
+    let integer = parse_u128_digits(int_part)?;
+    let wei = integer.checked_mul(scale).ok_or_else(|| eyre::eyre!("ether value overflows u128"))?;
+
+    // Right-pad the fractional part to exactly `decimals` digits before adding it in.
+    let fraction = if frac_part.is_empty() {
+        0
+    } else {
+        let padded = format!("{frac_part:0<width$}", width = decimals as usize);
+        parse_u128_digits(&padded)?
+    };
+
+    wei.checked_add(fraction).ok_or_else(|| eyre::eyre!("ether value overflows u128"))
+}
+
+/// Strip a unit suffix from `value`, tolerating a single separating space (`"2ether"` and
+/// `"2 ether"` both match `"ether"`).
+fn strip_unit<'a>(value: &'a str, unit: &str) -> Option<&'a str> {
+    let amount = value.strip_suffix(unit)?;
+    Some(amount.strip_suffix(' ').unwrap_or(amount))
+}
+
+/// Parse a run of decimal digits into a `u128`, accumulating with checked arithmetic so overflow is
+/// reported rather than wrapped. An empty string parses to `0`.
+fn parse_u128_digits(digits: &str) -> eyre::Result<u128> {
+    let mut acc: u128 = 0;
+    for b in digits.bytes() {
+        let digit = (b as char)
+            .to_digit(10)
+            .ok_or_else(|| eyre::eyre!("invalid digit in ether value: {:?}", b as char))?;
+        acc = acc
+            .checked_mul(10)
+            .and_then(|a| a.checked_add(digit as u128))
+            .ok_or_else(|| eyre::eyre!("ether value overflows u128"))?;
+    }
+    Ok(acc)
 }
 
 #[cfg(test)]
@@ -135,6 +844,139 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_ipv4_socket_addresses() {
+        let socket_addr = parse_socket_address("127.0.0.1:8545").unwrap();
+        assert_eq!(socket_addr, "127.0.0.1:8545".parse().unwrap());
+
+        // rejects out-of-range octets without overflow
+        assert!(parse_socket_address("127.0000000.0.1:8545").is_err());
+        assert!(parse_socket_address("256.0.0.1:8545").is_err());
+    }
+
+    #[test]
+    fn parse_ipv6_socket_addresses() {
+        let socket_addr = parse_socket_address("[::1]:8545").unwrap();
+        assert_eq!(socket_addr, "[::1]:8545".parse().unwrap());
+
+        // bare IPv6 defaults to port 0
+        let socket_addr = parse_socket_address("::1").unwrap();
+        assert_eq!(socket_addr.ip(), IpAddr::V6(Ipv6Addr::LOCALHOST));
+
+        // embedded IPv4 tail with `::` compression
+        let socket_addr = parse_socket_address("[2001:db8::192.0.2.33]:30303").unwrap();
+        assert_eq!(socket_addr, "[2001:db8::c000:221]:30303".parse().unwrap());
+
+        // scoped address with a named zone id is accepted but maps to scope id 0
+        let socket_addr = parse_socket_address("[fe80::1%eth0]:30303").unwrap();
+        match socket_addr {
+            SocketAddr::V6(v6) => assert_eq!(v6.scope_id(), 0),
+            _ => panic!("expected an IPv6 socket address"),
+        }
+        assert!(parse_socket_address("fe80::1%eth0").is_ok());
+
+        // a numeric zone is carried through as the scope id
+        let socket_addr = parse_socket_address("[fe80::1%3]:30303").unwrap();
+        match socket_addr {
+            SocketAddr::V6(v6) => assert_eq!(v6.scope_id(), 3),
+            _ => panic!("expected an IPv6 socket address"),
+        }
+    }
+
+    #[test]
+    fn parse_socket_address_rejects_misplaced_ipv4_tail() {
+        // an embedded IPv4 is only valid as the final two groups; trailing groups must be rejected
+        assert!(parse_socket_address("1:1.2.3.4:5").is_err());
+        assert!(parse_socket_address("1:2:1.2.3.4:5").is_err());
+        assert!(parse_ip_cidr("1:1.2.3.4:5/64").is_err());
+        // a genuine v4-mapped tail still parses
+        assert!(parse_socket_address("[::ffff:1.2.3.4]:80").is_ok());
+    }
+
+    #[test]
+    fn parse_socket_address_reports_offset() {
+        // the port overflows u16, so the failure position must point past the start, not at byte 0
+        let err = parse_socket_address("127.0.0.1:99999").unwrap_err();
+        match err {
+            SocketAddressParsingError::ParseAt { position, .. } => assert!(position > 0),
+            other => panic!("expected ParseAt, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_socket_address_rejects_noop_compression() {
+        // `::` must stand for at least one zero group
+        assert!(parse_socket_address("::1:2:3:4:5:6:7:8").is_err());
+        assert!(parse_socket_address("[1:2:3:4::5:6:7:8]:30303").is_err());
+        // a genuine compression still parses
+        assert!(parse_socket_address("[::1]:8545").is_ok());
+        assert!(parse_socket_address("[1:2:3:4:5:6:7::]:30303").is_ok());
+    }
+
+    #[test]
+    fn parse_socket_address_allow_dns_handles_ipv6_literals() {
+        // bracketed IPv6 and IPv4 literals resolve without going through IDNA
+        assert_eq!(
+            parse_socket_address_allow_dns("[::1]:8545").unwrap(),
+            "[::1]:8545".parse().unwrap()
+        );
+        assert_eq!(
+            parse_socket_address_allow_dns("127.0.0.1:8545").unwrap(),
+            "127.0.0.1:8545".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_host_classifies_literals_and_domains() {
+        assert_eq!(parse_host("127.0.0.1").unwrap(), Host::Ipv4(Ipv4Addr::LOCALHOST));
+        assert_eq!(parse_host("::1").unwrap(), Host::Ipv6(Ipv6Addr::LOCALHOST));
+        assert_eq!(parse_host("example.com").unwrap(), Host::Domain("example.com".to_string()));
+
+        // internationalized domains are normalized to Punycode
+        let Host::Domain(ascii) = parse_host("пример.рф").unwrap() else {
+            panic!("expected a domain");
+        };
+        assert!(ascii.starts_with("xn--"));
+
+        assert!(parse_host("").is_err());
+    }
+
+    #[test]
+    fn parse_socket_address_rejects_hostnames() {
+        // `parse_socket_address` never resolves DNS, so a hostname literal is an error
+        assert!(parse_socket_address("example.com:8545").is_err());
+    }
+
+    #[test]
+    fn parse_cidr_filters() {
+        let filter = parse_socket_filter("10.0.0.0/8").unwrap();
+        assert!(filter.contains("10.1.2.3".parse().unwrap()));
+        assert!(!filter.contains("11.0.0.1".parse().unwrap()));
+
+        let filter = parse_socket_filter("fe80::/10").unwrap();
+        assert!(filter.contains("fe80::1".parse().unwrap()));
+        assert!(!filter.contains("fec0::1".parse().unwrap()));
+
+        // prefix length out of range is rejected via checked bounds
+        assert!(parse_ip_cidr("10.0.0.0/33").is_err());
+        assert!(parse_ip_cidr("fe80::/129").is_err());
+    }
+
+    #[test]
+    fn parse_range_and_single_filters() {
+        let filter = parse_socket_filter("10.0.0.1-10.0.0.50").unwrap();
+        assert!(filter.contains("10.0.0.25".parse().unwrap()));
+        assert!(!filter.contains("10.0.0.51".parse().unwrap()));
+
+        let filter = parse_socket_filter("192.168.1.1").unwrap();
+        assert!(filter.contains("192.168.1.1".parse().unwrap()));
+        assert!(!filter.contains("192.168.1.2".parse().unwrap()));
+
+        // inverted and mixed-family ranges are rejected
+        assert!(parse_socket_filter("10.0.0.50-10.0.0.1").is_err());
+        assert!(parse_socket_filter("10.0.0.1-fe80::1").is_err());
+    }
+
     #[test]
     fn parse_ms_or_seconds() {
         let ms = parse_duration_from_secs_or_ms("5ms").unwrap();
@@ -168,5 +1010,20 @@ mod tests {
 
         // Test invalid input fails
         assert!(parse_ether_value("abc").is_err());
+
+        // Fractional values are exact (the old f64 path lost precision here)
+        assert_eq!(parse_ether_value("0.1").unwrap(), 100_000_000_000_000_000u128);
+
+        // Unit suffixes select the scale
+        assert_eq!(parse_ether_value("2ether").unwrap(), 2_000_000_000_000_000_000u128);
+        assert_eq!(parse_ether_value("1 gwei").unwrap(), 1_000_000_000u128);
+        assert_eq!(parse_ether_value("500000 wei").unwrap(), 500_000u128);
+
+        // A single wei is representable exactly
+        assert_eq!(parse_ether_value("1 wei").unwrap(), 1u128);
+
+        // Too many fractional digits for the unit is rejected
+        assert!(parse_ether_value("0.1 wei").is_err());
+        assert!(parse_ether_value("1.1234567890 gwei").is_err());
     }
 }